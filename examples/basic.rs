@@ -13,14 +13,19 @@ struct MyPool {
 #[async_trait]
 impl ManageConnection for MyPool {
     type Connection = TcpStream;
+    type Error = io::Error;
 
-    async fn connect(&self) -> io::Result<Self::Connection> {
+    async fn connect(&self) -> Result<Self::Connection, Self::Error> {
         TcpStream::connect(self.addr).await
     }
 
-    async fn check(&self, _conn: &mut Self::Connection) -> io::Result<()> {
+    async fn check(&self, _conn: &mut Self::Connection) -> Result<(), Self::Error> {
         Ok(())
     }
+
+    async fn has_broken(&self, _conn: &mut Self::Connection) -> bool {
+        false
+    }
 }
 
 #[tokio::main]
@@ -20,12 +20,13 @@
 //! #[async_trait]
 //! impl ManageConnection for MyPool {
 //!     type Connection = TcpStream;
+//!     type Error = io::Error;
 //!
-//!     async fn connect(&self) -> io::Result<Self::Connection> {
+//!     async fn connect(&self) -> Result<Self::Connection, Self::Error> {
 //!         TcpStream::connect(self.addr).await
 //!     }
 //!
-//!     async fn check(&self, _conn: &mut Self::Connection) -> io::Result<()> {
+//!     async fn check(&self, _conn: &mut Self::Connection) -> Result<(), Self::Error> {
 //!         Ok(())
 //!     }
 //!
@@ -37,29 +38,36 @@
 
 use std::collections::LinkedList;
 use std::fmt;
-use std::io;
 use std::marker::PhantomData;
 use std::ops::{Add, Deref, DerefMut};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, MutexGuard};
 use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
+use tokio::sync::{Notify, OwnedSemaphorePermit, Semaphore};
 use tokio::time::{delay_for, timeout};
 
+#[cfg(test)]
+mod test;
+
 /// A trait which provides connection-specific functionality.
 #[async_trait]
 pub trait ManageConnection: Send + Sync + 'static {
     /// The connection type this manager deals with.
     type Connection: Send + 'static;
 
+    /// The error type returned by this manager.
+    type Error: std::error::Error + Send + Sync + 'static;
+
     /// Attempts to create a new connection.
-    async fn connect(&self) -> io::Result<Self::Connection>;
+    async fn connect(&self) -> Result<Self::Connection, Self::Error>;
 
     /// Check if the connection is still valid, check background every `check_interval`.
     ///
     /// A standard implementation would check if a simple query like `PING` succee,
     /// if the `Connection` is broken, error should return.
-    async fn check(&self, conn: &mut Self::Connection) -> io::Result<()>;
+    async fn check(&self, conn: &mut Self::Connection) -> Result<(), Self::Error>;
 
     /// This will be called every time a connection is get from
     /// the pool, so it should be fast. If it returns `true`, the
@@ -67,8 +75,120 @@ pub trait ManageConnection: Send + Sync + 'static {
     async fn has_broken(&self, conn: &mut Self::Connection) -> bool;
 }
 
-fn other(msg: &str) -> io::Error {
-    io::Error::new(io::ErrorKind::Other, msg)
+/// The error type returned by `Pool::get` and `Pool::get_timeout`.
+pub enum Error<E> {
+    /// Timed out while waiting for a connection to become available.
+    Timeout,
+    /// An error returned by the connection manager.
+    Inner(E),
+}
+
+impl<E> fmt::Debug for Error<E>
+where
+    E: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Timeout => fmt.write_str("Timeout"),
+            Error::Inner(e) => fmt.debug_tuple("Inner").field(e).finish(),
+        }
+    }
+}
+
+impl<E> fmt::Display for Error<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Timeout => fmt.write_str("connection timeout"),
+            Error::Inner(e) => write!(fmt, "{}", e),
+        }
+    }
+}
+
+impl<E> std::error::Error for Error<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Timeout => None,
+            Error::Inner(e) => Some(e),
+        }
+    }
+}
+
+/// The error returned by `Pool::add` when a donated connection cannot be
+/// accepted. The connection is handed back so the caller can reuse or close
+/// it rather than having it silently dropped.
+pub enum AddError<C> {
+    /// The pool is already at `max_size`.
+    PoolFull(C),
+    /// The manager reported the connection as broken.
+    Broken(C),
+}
+
+impl<C> fmt::Debug for AddError<C> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AddError::PoolFull(_) => fmt.write_str("PoolFull"),
+            AddError::Broken(_) => fmt.write_str("Broken"),
+        }
+    }
+}
+
+impl<C> fmt::Display for AddError<C> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AddError::PoolFull(_) => fmt.write_str("pool is full"),
+            AddError::Broken(_) => fmt.write_str("connection is broken"),
+        }
+    }
+}
+
+/// The reason a connection was closed, reported via
+/// `PoolEvent::ConnectionClosed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClosedReason {
+    /// The connection exceeded `idle_timeout`.
+    IdleTimeout,
+    /// The connection exceeded `max_lifetime`.
+    MaxLifetime,
+    /// The manager reported the connection as broken.
+    Broken,
+    /// A donated connection was rejected because the pool was full.
+    PoolFull,
+}
+
+/// A lifecycle event emitted by the pool.
+///
+/// Register a handler with [`Builder::on_event`] to wire the pool into your
+/// own tracing or metrics without the crate taking a logging dependency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolEvent {
+    /// A new connection was established.
+    ConnectionCreated,
+    /// A connection was handed to a caller.
+    ConnectionAcquired,
+    /// A connection was returned to the pool.
+    ConnectionReturned,
+    /// A connection was closed.
+    ConnectionClosed {
+        /// Why the connection was closed.
+        reason: ClosedReason,
+    },
+    /// All idle connections were drained via [`Pool::clear`].
+    PoolCleared,
+}
+
+/// A handler for pool lifecycle events.
+pub trait PoolEventHandler<M>: Send + Sync + 'static
+where
+    M: ManageConnection,
+{
+    /// Called for each lifecycle event the pool emits.
+    fn handle(&self, event: PoolEvent);
 }
 
 /// A builder for a connection pool.
@@ -79,7 +199,11 @@ where
     pub max_lifetime: Option<Duration>,
     pub idle_timeout: Option<Duration>,
     pub connection_timeout: Option<Duration>,
+    pub check_interval: Option<Duration>,
     pub max_size: u32,
+    pub min_idle: Option<u32>,
+    pub max_connecting: u32,
+    event_handler: Option<Arc<dyn PoolEventHandler<M>>>,
     _pd: PhantomData<M>,
 }
 
@@ -93,6 +217,9 @@ where
             .field("max_lifetime", &self.max_lifetime)
             .field("idle_timeout", &self.idle_timeout)
             .field("connection_timeout", &self.connection_timeout)
+            .field("check_interval", &self.check_interval)
+            .field("min_idle", &self.min_idle)
+            .field("max_connecting", &self.max_connecting)
             .finish()
     }
 }
@@ -106,7 +233,11 @@ where
             max_lifetime: Some(Duration::from_secs(60 * 30)),
             idle_timeout: Some(Duration::from_secs(3 * 60)),
             connection_timeout: Some(Duration::from_secs(3)),
+            check_interval: Some(Duration::from_secs(3)),
             max_size: 0,
+            min_idle: None,
+            max_connecting: 2,
+            event_handler: None,
             _pd: PhantomData,
         }
     }
@@ -174,6 +305,23 @@ where
         }
     }
 
+    /// Sets how often the background task checks idle connections.
+    ///
+    /// Idle connections are validated and reaped for `idle_timeout` /
+    /// `max_lifetime` on this interval.
+    ///
+    /// Defaults to 3 seconds.
+    ///
+    /// use default if `check_interval` is the zero `Duration`.
+    pub fn check_interval(mut self, check_interval: Option<Duration>) -> Self {
+        if check_interval == Some(Duration::from_secs(0)) {
+            self
+        } else {
+            self.check_interval = check_interval;
+            self
+        }
+    }
+
     /// Sets the maximum number of connections managed by the pool.
     ///
     /// Defaults to 10.
@@ -184,6 +332,40 @@ where
         self
     }
 
+    /// Sets the minimum number of idle connections the pool maintains.
+    ///
+    /// The background maintenance task eagerly establishes connections until
+    /// this many are idle, pre-warming the pool so the first `get` after an
+    /// idle period doesn't pay full connect latency.
+    ///
+    /// Defaults to `None`, leaving the pool empty until the first `get`.
+    pub fn min_idle(mut self, min_idle: Option<u32>) -> Self {
+        self.min_idle = min_idle;
+        self
+    }
+
+    /// Sets the maximum number of connections established concurrently.
+    ///
+    /// Bounds how many `connect` futures run at once so a burst of misses
+    /// doesn't stampede the backend; callers beyond the cap wait until an
+    /// establishment finishes or a connection is returned to the pool.
+    ///
+    /// Defaults to 2.
+    pub fn max_connecting(mut self, max_connecting: u32) -> Self {
+        self.max_connecting = max_connecting;
+        self
+    }
+
+    /// Registers a handler for pool lifecycle events.
+    ///
+    /// The handler receives [`PoolEvent`]s as connections are created,
+    /// acquired, returned, and closed, letting callers observe the pool
+    /// without the crate taking a logging dependency.
+    pub fn on_event(mut self, handler: Arc<dyn PoolEventHandler<M>>) -> Self {
+        self.event_handler = Some(handler);
+        self
+    }
+
     /// Consumes the builder, returning a new, initialized pool.
     pub fn build(&self, manager: M) -> Pool<M>
     where
@@ -192,6 +374,13 @@ where
         let intervals = PoolInternals {
             conns: LinkedList::new(),
             active: 0,
+            connecting: 0,
+        };
+
+        let semaphore = if self.max_size == 0 {
+            None
+        } else {
+            Some(Arc::new(Semaphore::new(self.max_size as usize)))
         };
 
         let shared = SharedPool {
@@ -199,7 +388,14 @@ where
             max_lifetime: self.max_lifetime,
             idle_timeout: self.idle_timeout,
             connection_timeout: self.connection_timeout,
+            check_interval: self.check_interval,
             max_size: self.max_size,
+            min_idle: self.min_idle,
+            max_connecting: self.max_connecting,
+            semaphore,
+            notify: Notify::new(),
+            statistics: Statistics::default(),
+            event_handler: self.event_handler.clone(),
             manager,
         };
 
@@ -214,6 +410,9 @@ where
     M: ManageConnection,
 {
     conn: Option<IdleConn<M::Connection>>,
+    // Held for the lifetime of the checkout; dropping it frees a slot and
+    // wakes the next waiter in FIFO order. `None` in unbounded mode.
+    _permit: Option<OwnedSemaphorePermit>,
     pool: Pool<M>,
 }
 
@@ -283,6 +482,26 @@ where
         self.interval().conns.len()
     }
 
+    /// Returns a snapshot of the pool's current state and lifetime counters.
+    pub fn state(&self) -> State {
+        let stats = &self.0.statistics;
+        let internals = self.interval();
+        State {
+            connections: internals.active,
+            idle_connections: internals.conns.len() as u32,
+            waiters: stats.waiters.load(Ordering::Relaxed),
+            connections_created: stats.connections_created.load(Ordering::Relaxed),
+            connections_closed_broken: stats.connections_closed_broken.load(Ordering::Relaxed),
+            connections_closed_idle_timeout: stats
+                .connections_closed_idle_timeout
+                .load(Ordering::Relaxed),
+            connections_closed_max_lifetime: stats
+                .connections_closed_max_lifetime
+                .load(Ordering::Relaxed),
+            get_timeouts: stats.get_timeouts.load(Ordering::Relaxed),
+        }
+    }
+
     fn incr_active(&self) {
         self.interval().active += 1;
     }
@@ -295,6 +514,54 @@ where
         self.interval().conns.pop_front()
     }
 
+    /// Reserves an establishment slot if fewer than `max_connecting` are in
+    /// flight. Returns `false` if the cap is already reached.
+    fn try_begin_connect(&self) -> bool {
+        let mut internals = self.interval();
+        if internals.connecting >= self.0.max_connecting {
+            false
+        } else {
+            internals.connecting += 1;
+            true
+        }
+    }
+
+    /// Releases an establishment slot and wakes a waiter.
+    fn end_connect(&self) {
+        self.interval().connecting -= 1;
+        self.0.notify.notify();
+    }
+
+    /// Returns the time left until `deadline`, or `None` when no connection
+    /// timeout is configured (wait indefinitely). An elapsed deadline yields
+    /// `Some(Duration::from_secs(0))`, which times out immediately.
+    fn remaining(&self, deadline: Option<Instant>) -> Option<Duration> {
+        deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Emits a lifecycle event to the registered handler, if any.
+    fn emit(&self, event: PoolEvent) {
+        if let Some(handler) = &self.0.event_handler {
+            handler.handle(event);
+        }
+    }
+
+    /// Drains all idle connections and fires a [`PoolEvent::PoolCleared`].
+    ///
+    /// Useful for forcing reconnection after a detected server failover.
+    /// Connections currently checked out are left untouched and return to the
+    /// pool as usual when dropped.
+    pub fn clear(&self) {
+        let drained = {
+            let mut internals = self.interval();
+            let drained = std::mem::take(&mut internals.conns);
+            internals.active -= drained.len() as u32;
+            drained
+        };
+        drop(drained);
+        self.emit(PoolEvent::PoolCleared);
+    }
+
     fn push_back(&mut self, conn: IdleConn<M::Connection>) {
         self.interval().conns.push_back(conn);
     }
@@ -322,13 +589,31 @@ where
 
     async fn check(mut self) {
         loop {
-            delay_for(Duration::from_secs(3)).await;
+            delay_for(self.0.check_interval.unwrap_or(Duration::from_secs(3))).await;
 
             let n = self.idle_count();
             for _ in 0..n {
                 if let Some(mut conn) = self.pop_front() {
-                    if self.exceed_idle_timeout(&conn) || self.exceed_max_lifetime(&conn) {
+                    if self.exceed_idle_timeout(&conn) {
+                        self.decr_active();
+                        self.0
+                            .statistics
+                            .connections_closed_idle_timeout
+                            .fetch_add(1, Ordering::Relaxed);
+                        self.emit(PoolEvent::ConnectionClosed {
+                            reason: ClosedReason::IdleTimeout,
+                        });
+                        continue;
+                    }
+                    if self.exceed_max_lifetime(&conn) {
                         self.decr_active();
+                        self.0
+                            .statistics
+                            .connections_closed_max_lifetime
+                            .fetch_add(1, Ordering::Relaxed);
+                        self.emit(PoolEvent::ConnectionClosed {
+                            reason: ClosedReason::MaxLifetime,
+                        });
                         continue;
                     }
 
@@ -339,6 +624,13 @@ where
                         }
                         Err(_) => {
                             self.decr_active();
+                            self.0
+                                .statistics
+                                .connections_closed_broken
+                                .fetch_add(1, Ordering::Relaxed);
+                            self.emit(PoolEvent::ConnectionClosed {
+                                reason: ClosedReason::Broken,
+                            });
                         }
                     }
                     continue;
@@ -346,15 +638,55 @@ where
 
                 break;
             }
+
+            self.maintain_min_idle().await;
         }
     }
 
-    fn exceed_limit(&self) -> bool {
-        let max_size = self.0.max_size;
-        if max_size > 0 && self.interval().active > max_size {
-            true
-        } else {
-            false
+    /// Eagerly establishes connections until `idle_count` reaches `min_idle`,
+    /// honoring `max_size` and the `max_connecting` cap. Run after reaping so
+    /// counts stay consistent under the lock.
+    async fn maintain_min_idle(&self) {
+        let min_idle = match self.0.min_idle {
+            Some(min_idle) => min_idle as usize,
+            None => return,
+        };
+
+        while self.idle_count() < min_idle {
+            if self.0.max_size > 0 && self.interval().active >= self.0.max_size {
+                break;
+            }
+            if !self.try_begin_connect() {
+                break;
+            }
+
+            let result = self.0.manager.connect().await;
+            self.end_connect();
+            match result {
+                Ok(conn) => {
+                    let mut internals = self.interval();
+                    // Re-check under the lock: concurrent `get`s may have
+                    // filled the pool while we awaited `connect`. Drop the
+                    // surplus connection rather than exceed `max_size`.
+                    if self.0.max_size > 0 && internals.active >= self.0.max_size {
+                        break;
+                    }
+                    internals.active += 1;
+                    internals.conns.push_back(IdleConn {
+                        conn,
+                        last_visited: Instant::now(),
+                        created: Instant::now(),
+                    });
+                    drop(internals);
+                    self.0
+                        .statistics
+                        .connections_created
+                        .fetch_add(1, Ordering::Relaxed);
+                    self.emit(PoolEvent::ConnectionCreated);
+                    self.0.notify.notify();
+                }
+                Err(_) => break,
+            }
         }
     }
 
@@ -364,24 +696,15 @@ where
     pub async fn get_timeout(
         &self,
         connection_timeout: Option<Duration>,
-    ) -> io::Result<M::Connection> {
+    ) -> Result<M::Connection, Error<M::Error>> {
         if let Some(connection_timeout) = connection_timeout {
-            let conn = match timeout(connection_timeout, self.0.manager.connect()).await {
-                Ok(s) => match s {
-                    Ok(s) => s,
-                    Err(e) => {
-                        return Err(other(&e.to_string()));
-                    }
-                },
-                Err(e) => {
-                    return Err(other(&e.to_string()));
-                }
-            };
-
-            Ok(conn)
+            match timeout(connection_timeout, self.0.manager.connect()).await {
+                Ok(Ok(conn)) => Ok(conn),
+                Ok(Err(e)) => Err(Error::Inner(e)),
+                Err(_) => Err(Error::Timeout),
+            }
         } else {
-            let conn = self.0.manager.connect().await?;
-            Ok(conn)
+            self.0.manager.connect().await.map_err(Error::Inner)
         }
     }
 
@@ -389,33 +712,164 @@ where
     ///
     /// Waits for at most the configured connection timeout before returning an
     /// error.
-    pub async fn get(&self) -> io::Result<Connection<M>> {
-        if let Some(conn) = self.pop_front() {
+    pub async fn get(&self) -> Result<Connection<M>, Error<M::Error>> {
+        // A single deadline governs the whole call so that waiting on the
+        // semaphore, parking on the `max_connecting` cap, and establishing a
+        // connection together honor `connection_timeout`.
+        let deadline = self.0.connection_timeout.map(|t| Instant::now() + t);
+
+        // Wait for a free slot rather than erroring at capacity. Acquiring a
+        // permit here is what bounds the pool; the permit rides along inside
+        // the returned `Connection` and frees the slot on drop.
+        let permit = match &self.0.semaphore {
+            Some(semaphore) => {
+                let acquire = semaphore.clone().acquire_owned();
+                self.0.statistics.waiters.fetch_add(1, Ordering::Relaxed);
+                let permit = match self.remaining(deadline) {
+                    Some(remaining) => match timeout(remaining, acquire).await {
+                        Ok(permit) => Some(permit),
+                        Err(_) => {
+                            self.0.statistics.waiters.fetch_sub(1, Ordering::Relaxed);
+                            self.0.statistics.get_timeouts.fetch_add(1, Ordering::Relaxed);
+                            return Err(Error::Timeout);
+                        }
+                    },
+                    None => Some(acquire.await),
+                };
+                self.0.statistics.waiters.fetch_sub(1, Ordering::Relaxed);
+                permit
+            }
+            None => None,
+        };
+
+        loop {
+            // Discard any idle connection the manager reports as broken rather
+            // than handing a dead connection to the caller.
+            while let Some(mut conn) = self.pop_front() {
+                if self.0.manager.has_broken(&mut conn.conn).await {
+                    self.decr_active();
+                    self.0
+                        .statistics
+                        .connections_closed_broken
+                        .fetch_add(1, Ordering::Relaxed);
+                    self.emit(PoolEvent::ConnectionClosed {
+                        reason: ClosedReason::Broken,
+                    });
+                    continue;
+                }
+
+                self.emit(PoolEvent::ConnectionAcquired);
+                return Ok(Connection {
+                    conn: Some(conn),
+                    _permit: permit,
+                    pool: self.clone(),
+                });
+            }
+
+            // No idle connection available; establish a new one while honoring
+            // the `max_connecting` cap. If the cap is reached, wait for an
+            // in-flight establishment to finish or a connection to be returned
+            // to the idle list, bounded by the remaining connection timeout.
+            if !self.try_begin_connect() {
+                // Callers parked here are pending acquirers too, so they count
+                // towards `waiters` just like those waiting on the semaphore.
+                self.0.statistics.waiters.fetch_add(1, Ordering::Relaxed);
+                let elapsed = match self.remaining(deadline) {
+                    Some(remaining) => {
+                        timeout(remaining, self.0.notify.notified()).await.is_err()
+                    }
+                    None => {
+                        self.0.notify.notified().await;
+                        false
+                    }
+                };
+                self.0.statistics.waiters.fetch_sub(1, Ordering::Relaxed);
+                if elapsed {
+                    self.0.statistics.get_timeouts.fetch_add(1, Ordering::Relaxed);
+                    return Err(Error::Timeout);
+                }
+                continue;
+            }
+
+            let result = self.get_timeout(self.remaining(deadline)).await;
+            self.end_connect();
+            let conn = match result {
+                Ok(conn) => conn,
+                Err(e) => {
+                    if let Error::Timeout = e {
+                        self.0.statistics.get_timeouts.fetch_add(1, Ordering::Relaxed);
+                    }
+                    return Err(e);
+                }
+            };
+            self.incr_active();
+            self.0
+                .statistics
+                .connections_created
+                .fetch_add(1, Ordering::Relaxed);
+            self.emit(PoolEvent::ConnectionCreated);
+            self.emit(PoolEvent::ConnectionAcquired);
             return Ok(Connection {
-                conn: Some(conn),
+                conn: Some(IdleConn {
+                    conn,
+                    last_visited: Instant::now(),
+                    created: Instant::now(),
+                }),
+                _permit: permit,
                 pool: self.clone(),
             });
         }
+    }
+
+    /// Donates an externally established connection to the pool.
+    ///
+    /// Useful for pre-warming at startup or returning a connection created
+    /// outside the pool. The connection is validated with `has_broken` and
+    /// checked against `max_size`; on either failure it is handed back inside
+    /// the returned `AddError` so nothing is silently dropped.
+    pub async fn add(&self, mut conn: M::Connection) -> Result<(), AddError<M::Connection>> {
+        if self.0.max_size > 0 && self.interval().active >= self.0.max_size {
+            self.emit(PoolEvent::ConnectionClosed {
+                reason: ClosedReason::PoolFull,
+            });
+            return Err(AddError::PoolFull(conn));
+        }
 
-        if self.exceed_limit() {
-            return Err(other("exceed limit"));
+        if self.0.manager.has_broken(&mut conn).await {
+            self.emit(PoolEvent::ConnectionClosed {
+                reason: ClosedReason::Broken,
+            });
+            return Err(AddError::Broken(conn));
         }
 
-        let conn = self.get_timeout(self.0.connection_timeout).await?;
-        self.incr_active();
-        return Ok(Connection {
-            conn: Some(IdleConn {
-                conn,
-                last_visited: Instant::now(),
-                created: Instant::now(),
-            }),
-            pool: self.clone(),
+        let mut internals = self.interval();
+        // Re-check under the lock: capacity may have changed while awaiting
+        // `has_broken`.
+        if self.0.max_size > 0 && internals.active >= self.0.max_size {
+            drop(internals);
+            self.emit(PoolEvent::ConnectionClosed {
+                reason: ClosedReason::PoolFull,
+            });
+            return Err(AddError::PoolFull(conn));
+        }
+        internals.active += 1;
+        internals.conns.push_back(IdleConn {
+            conn,
+            last_visited: Instant::now(),
+            created: Instant::now(),
         });
+        drop(internals);
+        self.0.notify.notify();
+        Ok(())
     }
 
     fn put(&mut self, mut conn: IdleConn<M::Connection>) {
         conn.last_visited = Instant::now();
         self.push_back(conn);
+        self.emit(PoolEvent::ConnectionReturned);
+        // Wake a caller that is waiting on the establishment cap so it can
+        // reuse the returned connection instead of opening a new one.
+        self.0.notify.notify();
     }
 }
 
@@ -427,10 +881,51 @@ where
     max_lifetime: Option<Duration>,
     idle_timeout: Option<Duration>,
     connection_timeout: Option<Duration>,
+    check_interval: Option<Duration>,
     max_size: u32,
+    min_idle: Option<u32>,
+    max_connecting: u32,
+    semaphore: Option<Arc<Semaphore>>,
+    notify: Notify,
+    statistics: Statistics,
+    event_handler: Option<Arc<dyn PoolEventHandler<M>>>,
     manager: M,
 }
 
+/// Counters backing `State`: the current number of waiters plus cumulative
+/// lifetime totals.
+#[derive(Default)]
+struct Statistics {
+    waiters: AtomicU64,
+    connections_created: AtomicU64,
+    connections_closed_broken: AtomicU64,
+    connections_closed_idle_timeout: AtomicU64,
+    connections_closed_max_lifetime: AtomicU64,
+    get_timeouts: AtomicU64,
+}
+
+/// A snapshot of a pool's runtime state.
+#[derive(Debug, Clone)]
+pub struct State {
+    /// Connections currently managed by the pool (idle plus checked out).
+    pub connections: u32,
+    /// Connections sitting idle in the pool.
+    pub idle_connections: u32,
+    /// Callers currently waiting for a connection to become available.
+    pub waiters: u64,
+    /// Total connections ever created by the pool.
+    pub connections_created: u64,
+    /// Total connections closed after being reported broken.
+    pub connections_closed_broken: u64,
+    /// Total connections closed after exceeding `idle_timeout`.
+    pub connections_closed_idle_timeout: u64,
+    /// Total connections closed after exceeding `max_lifetime`.
+    pub connections_closed_max_lifetime: u64,
+    /// Total `get` calls that timed out. Direct calls to `get_timeout` are
+    /// not counted.
+    pub get_timeouts: u64,
+}
+
 struct IdleConn<C> {
     conn: C,
     last_visited: Instant,
@@ -440,4 +935,5 @@ struct IdleConn<C> {
 struct PoolInternals<C> {
     conns: LinkedList<IdleConn<C>>,
     active: u32,
+    connecting: u32,
 }
@@ -1,7 +1,9 @@
 use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-use crate::{ManageConnection, Pool};
+use crate::{ManageConnection, Pool, PoolEvent, PoolEventHandler};
 
 use async_trait::async_trait;
 use tokio::time::delay_for;
@@ -17,8 +19,9 @@ struct FakeManager {
 #[async_trait]
 impl ManageConnection for FakeManager {
     type Connection = FakeConnection;
+    type Error = io::Error;
 
-    async fn connect(&self) -> io::Result<Self::Connection> {
+    async fn connect(&self) -> Result<Self::Connection, Self::Error> {
         if let Some(d) = self.sleep {
             delay_for(d).await;
         }
@@ -26,22 +29,34 @@ impl ManageConnection for FakeManager {
         Ok(FakeConnection)
     }
 
-    async fn check(&self, _conn: &mut Self::Connection) -> io::Result<()> {
+    async fn check(&self, _conn: &mut Self::Connection) -> Result<(), Self::Error> {
         Ok(())
     }
+
+    async fn has_broken(&self, _conn: &mut Self::Connection) -> bool {
+        false
+    }
 }
 
 #[tokio::test]
 async fn test_max_size_ok() {
     let manager = FakeManager::default();
-    let pool = Pool::builder().max_size(5).build(manager);
+    let pool = Pool::builder()
+        .max_size(5)
+        .connection_timeout(Some(Duration::from_millis(100)))
+        .build(manager);
     let mut conns = vec![];
     for _ in 0..5 {
         conns.push(pool.get().await.unwrap());
     }
     assert_eq!(pool.interval().active, 5);
+    // At capacity `get` waits for a free slot and times out rather than
+    // erroring immediately.
     assert!(pool.get().await.is_err());
     assert_eq!(pool.interval().active, 5);
+    // Freeing a checkout hands back its slot, so the next `get` succeeds.
+    conns.pop();
+    assert!(pool.get().await.is_ok());
 }
 
 #[tokio::test]
@@ -111,3 +126,142 @@ async fn test_max_lifetime() {
     delay_for(Duration::from_secs(2)).await;
     assert_eq!(pool.interval().active, 0);
 }
+
+struct DropConnection {
+    dropped: Arc<AtomicBool>,
+}
+
+impl Drop for DropConnection {
+    fn drop(&mut self) {
+        self.dropped.store(true, Ordering::SeqCst);
+    }
+}
+
+struct BrokenConnectionManager {
+    dropped: Arc<AtomicBool>,
+    broken: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl ManageConnection for BrokenConnectionManager {
+    type Connection = DropConnection;
+    type Error = io::Error;
+
+    async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+        Ok(DropConnection {
+            dropped: self.dropped.clone(),
+        })
+    }
+
+    async fn check(&self, _conn: &mut Self::Connection) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn has_broken(&self, _conn: &mut Self::Connection) -> bool {
+        self.broken.load(Ordering::SeqCst)
+    }
+}
+
+#[tokio::test]
+async fn test_has_broken_discard() {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let broken = Arc::new(AtomicBool::new(false));
+    let manager = BrokenConnectionManager {
+        dropped: dropped.clone(),
+        broken: broken.clone(),
+    };
+    let pool = Pool::builder().max_size(2).build(manager);
+
+    // Establish one connection and return it to the idle list.
+    let conn = pool.get().await.unwrap();
+    assert_eq!(pool.interval().active, 1);
+    drop(conn);
+
+    // Now every idle connection reports broken: the stale one is dropped and
+    // `get` transparently establishes a healthy replacement.
+    broken.store(true, Ordering::SeqCst);
+    let conn = pool.get().await.unwrap();
+    assert!(dropped.load(Ordering::SeqCst));
+    broken.store(false, Ordering::SeqCst);
+    drop(conn);
+    assert_eq!(pool.interval().active, 1);
+}
+
+#[tokio::test]
+async fn test_add() {
+    let manager = FakeManager::default();
+    let pool = Pool::builder().max_size(2).build(manager);
+
+    pool.add(FakeConnection).await.unwrap();
+    assert_eq!(pool.interval().active, 1);
+    assert_eq!(pool.idle_count(), 1);
+
+    pool.add(FakeConnection).await.unwrap();
+    assert_eq!(pool.interval().active, 2);
+
+    // At capacity the donated connection is handed back.
+    match pool.add(FakeConnection).await {
+        Err(crate::AddError::PoolFull(_)) => {}
+        _ => panic!("expected PoolFull"),
+    }
+}
+
+#[tokio::test]
+async fn test_state() {
+    let manager = FakeManager::default();
+    let pool = Pool::builder().max_size(3).build(manager);
+
+    let conn1 = pool.get().await.unwrap();
+    let conn2 = pool.get().await.unwrap();
+    let state = pool.state();
+    assert_eq!(state.connections, 2);
+    assert_eq!(state.idle_connections, 0);
+    assert_eq!(state.connections_created, 2);
+
+    drop(conn1);
+    drop(conn2);
+    let state = pool.state();
+    assert_eq!(state.idle_connections, 2);
+}
+
+#[tokio::test]
+async fn test_min_idle() {
+    let manager = FakeManager::default();
+    let pool = Pool::builder().max_size(5).min_idle(Some(3)).build(manager);
+
+    // The maintenance task runs on its interval and pre-warms the pool.
+    delay_for(Duration::from_secs(4)).await;
+    assert_eq!(pool.state().idle_connections, 3);
+}
+
+struct Recorder {
+    events: Arc<Mutex<Vec<PoolEvent>>>,
+}
+
+impl PoolEventHandler<FakeManager> for Recorder {
+    fn handle(&self, event: PoolEvent) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+#[tokio::test]
+async fn test_events() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let manager = FakeManager::default();
+    let pool = Pool::builder()
+        .max_size(2)
+        .on_event(Arc::new(Recorder {
+            events: events.clone(),
+        }))
+        .build(manager);
+
+    let conn = pool.get().await.unwrap();
+    drop(conn);
+    pool.clear();
+
+    let recorded = events.lock().unwrap().clone();
+    assert!(recorded.contains(&PoolEvent::ConnectionCreated));
+    assert!(recorded.contains(&PoolEvent::ConnectionAcquired));
+    assert!(recorded.contains(&PoolEvent::ConnectionReturned));
+    assert!(recorded.contains(&PoolEvent::PoolCleared));
+}